@@ -28,6 +28,30 @@ impl NdiSource {
     }
 }
 
+/// Build an `ndi::find::Find` from the optional discovery settings exposed to Python.
+///
+/// Shared between `NdiFinder::new` and `NdiReceiver::connect_to_source` so both entry
+/// points honour the same `show_local_sources`/`groups`/`extra_ips` knobs.
+pub(crate) fn build_finder(
+    show_local_sources: bool,
+    groups: Option<String>,
+    extra_ips: Option<String>,
+) -> PyResult<ndi::find::Find> {
+    let mut builder = ndi::find::FindBuilder::new().show_local_sources(show_local_sources);
+
+    if let Some(groups) = groups {
+        builder = builder.groups(groups);
+    }
+
+    if let Some(extra_ips) = extra_ips {
+        builder = builder.extra_ips(extra_ips);
+    }
+
+    builder
+        .build()
+        .map_err(|_| PyRuntimeError::new_err("Failed to create NDI finder"))
+}
+
 /// Python class representing an NDI finder
 #[pyclass]
 struct NdiFinder {
@@ -36,17 +60,29 @@ struct NdiFinder {
 
 #[pymethods]
 impl NdiFinder {
+    /// Create a new finder.
+    ///
+    /// Args:
+    ///     show_local_sources: include sources originating on this machine (default: true)
+    ///     groups: comma-separated list of NDI groups to restrict discovery to
+    ///     extra_ips: comma-separated list of unicast IPs/hostnames to probe in addition
+    ///                to mDNS discovery, for subnets where multicast does not propagate
     #[new]
-    fn new() -> PyResult<Self> {
+    #[pyo3(signature = (show_local_sources = true, groups = None, extra_ips = None))]
+    fn new(
+        show_local_sources: bool,
+        groups: Option<String>,
+        extra_ips: Option<String>,
+    ) -> PyResult<Self> {
+        // Make sure the SDK library itself could be found before asking the `ndi` crate
+        // to initialize against it, so a missing install raises a diagnosable error.
+        crate::runtime::ensure_loaded()?;
+
         // Initialize the NDI system if not already initialized
         match ndi::initialize() {
             Ok(_) => {
-                // Create a finder with default settings
-                let find_create = ndi::find::FindBuilder::new().build();
-                match find_create {
-                    Ok(finder) => Ok(NdiFinder { finder: Some(finder) }),
-                    Err(_) => Err(PyRuntimeError::new_err("Failed to create NDI finder")),
-                }
+                let finder = build_finder(show_local_sources, groups, extra_ips)?;
+                Ok(NdiFinder { finder: Some(finder) })
             },
             Err(_) => Err(PyRuntimeError::new_err(
                 "Failed to initialize NDI runtime. Make sure the NDI SDK is installed on your system.",
@@ -91,11 +127,57 @@ impl NdiFinder {
         Ok(py_list.into())
     }
 
+    /// Block until at least one source is visible or the timeout elapses.
+    ///
+    /// `ndi::find::Find` has no native "wait for the list to change" call; this polls
+    /// `current_sources`, which itself loops internally until sources appear or
+    /// `timeout_ms` elapses. Returns true if at least one source was found in time,
+    /// false if the call timed out with none visible.
+    fn wait_for_sources(&self, timeout_ms: u32) -> PyResult<bool> {
+        let finder = match &self.finder {
+            Some(f) => f,
+            None => return Err(PyRuntimeError::new_err("Finder is not initialized")),
+        };
+
+        Ok(finder.current_sources(timeout_ms as u128).is_ok())
+    }
+
+    /// Get the names of the sources currently known to this finder, without waiting for
+    /// the list to change. `ndi::Source` only exposes a display name, not a URL address.
+    fn get_current_sources(&self) -> PyResult<Vec<String>> {
+        let finder = match &self.finder {
+            Some(f) => f,
+            None => return Err(PyRuntimeError::new_err("Finder is not initialized")),
+        };
+
+        let sources = finder
+            .current_sources(0)
+            .map_err(|_| PyRuntimeError::new_err("Failed to get current sources"))?;
+
+        Ok(sources.iter().map(|source| source.get_name()).collect())
+    }
+
     /// Free resources associated with the finder
     fn close(&mut self) -> PyResult<()> {
         self.finder = None;
         Ok(())
     }
+
+    fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    /// Context manager exit: always closes the finder deterministically.
+    #[pyo3(signature = (_exc_type = None, _exc_value = None, _traceback = None))]
+    fn __exit__(
+        &mut self,
+        _exc_type: Option<&PyAny>,
+        _exc_value: Option<&PyAny>,
+        _traceback: Option<&PyAny>,
+    ) -> PyResult<bool> {
+        self.close()?;
+        Ok(false)
+    }
 }
 
 /// Register discovery-related Python functions and classes
@@ -1,5 +1,13 @@
+// Built as a single version-agnostic wheel via pyo3's `abi3-py311` feature (see
+// Cargo.toml). The floor is 3.11, not the lower `abi3-py38` the rest of the API could
+// otherwise support, because `NdiVideoFrame`/`NdiAudioFrame`'s buffer protocol support
+// (see receiver.rs) relies on `Py_buffer`, which only joined the limited API in 3.11;
+// building against an older floor would make that buffer protocol code unsound under
+// the stable ABI.
+
 mod discovery;
 mod receiver;
+mod runtime;
 mod sender;
 mod utils;
 
@@ -39,6 +47,25 @@ fn ndirust_py(_py: Python, m: &PyModule) -> PyResult<()> {
     sender::register_sender_functions(sender_module)?;
     m.add_submodule(sender_module)?;
 
+    let utils_module = PyModule::new(_py, "utils")?;
+    utils::register_utility_functions(utils_module)?;
+    m.add_submodule(utils_module)?;
+
+    // SDK locator functions (`is_sdk_available`, `get_sdk_version`) live at module level
+    // rather than in a submodule, since they're meant to be checked before doing
+    // anything else with the bindings.
+    runtime::register_runtime_functions(_py, m)?;
+
+    // Probe for the NDI runtime library at import time so `is_sdk_available()` reflects
+    // a warm lookup instead of surprising the caller with first-use latency, and so a
+    // successful lookup is cached for every `NdiFinder`/`NdiReceiver`/`NdiSender`
+    // constructed afterwards (a failed one is retried by each of them). The outcome is
+    // discarded here because a failure doesn't break the import (see the module header in
+    // runtime.rs): the `ndi` crate links against a bundled runtime library at build time,
+    // so by the time this line runs the OS loader has already resolved it. The constructors
+    // still raise `NdiSdkNotFoundError` while this lookup fails.
+    let _ = runtime::ensure_loaded();
+
     // Add module-level attributes
     let sys = PyModule::import(_py, "sys")?;
     let version = get_version_info()?;
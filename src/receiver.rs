@@ -2,8 +2,13 @@
 
 use pyo3::prelude::*;
 use ndi;
-use pyo3::exceptions::PyRuntimeError;
+use pyo3::exceptions::{PyBufferError, PyRuntimeError};
 use pyo3::types::{PyBytes, PyDict};
+use pyo3::AsPyPointer;
+use numpy::ndarray::{ArrayView2, ArrayView3};
+use numpy::{PyArray2, PyArray3};
+use std::os::raw::c_int;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 
 /// Frame type enum exposed to Python
@@ -17,33 +22,146 @@ pub enum FrameType {
     Error = 4,
 }
 
-/// Python class representing an NDI video frame
+/// Bandwidth mode exposed to Python, mirroring the SDK's `NDIlib_recv_bandwidth_e`.
+///
+/// `Lowest` requests a preview-only stream, which is useful for multi-source monitoring
+/// where decoding full-resolution video would be wasteful.
 #[pyclass]
+#[derive(Clone, Copy)]
+pub enum RecvBandwidth {
+    MetadataOnly = 0,
+    AudioOnly = 1,
+    Lowest = 2,
+    Highest = 3,
+}
+
+impl From<RecvBandwidth> for ndi::recv::RecvBandwidth {
+    fn from(value: RecvBandwidth) -> Self {
+        match value {
+            RecvBandwidth::MetadataOnly => ndi::recv::RecvBandwidth::MetadataOnly,
+            RecvBandwidth::AudioOnly => ndi::recv::RecvBandwidth::AudioOnly,
+            RecvBandwidth::Lowest => ndi::recv::RecvBandwidth::Lowest,
+            RecvBandwidth::Highest => ndi::recv::RecvBandwidth::Highest,
+        }
+    }
+}
+
+/// Preferred color format exposed to Python, mirroring `NDIlib_recv_color_format_e`.
+#[pyclass]
+#[derive(Clone, Copy)]
+pub enum RecvColorFormat {
+    BgrxBgra = 0,
+    UyvyBgra = 1,
+    Fastest = 2,
+    Best = 3,
+}
+
+impl From<RecvColorFormat> for ndi::recv::RecvColorFormat {
+    fn from(value: RecvColorFormat) -> Self {
+        match value {
+            RecvColorFormat::BgrxBgra => ndi::recv::RecvColorFormat::BGRX_BGRA,
+            RecvColorFormat::UyvyBgra => ndi::recv::RecvColorFormat::UYVY_BGRA,
+            RecvColorFormat::Fastest => ndi::recv::RecvColorFormat::Fastest,
+            RecvColorFormat::Best => ndi::recv::RecvColorFormat::Best,
+        }
+    }
+}
+
+/// Compute the exact byte size of a received video frame's pixel buffer, keyed off its
+/// FourCC and field mode. Unlike a generic `stride * height` guess this accounts for
+/// planar formats (which pack extra chroma/alpha planes) and single-field frames (which
+/// carry half the scanlines of a full frame). Every FourCC the `ndi` crate reports is
+/// sized here; returns `None` when the frame has no line stride, so callers never hand
+/// out a guessed number of bytes.
+fn video_frame_data_size(video: &ndi::VideoData) -> Option<usize> {
+    let stride = video.line_stride_in_bytes()? as usize;
+    let width = video.width() as usize;
+    let mut height = video.height() as usize;
+
+    // A single field frame only carries half the scanlines of a full frame.
+    if matches!(
+        video.frame_format_type(),
+        ndi::FrameFormatType::Field0 | ndi::FrameFormatType::Field1
+    ) {
+        height /= 2;
+    }
+
+    use ndi::FourCCVideoType;
+
+    match video.four_cc() {
+        // Packed formats: a single plane of `stride` bytes per line.
+        FourCCVideoType::UYVY
+        | FourCCVideoType::BGRA
+        | FourCCVideoType::RGBA
+        | FourCCVideoType::BGRX
+        | FourCCVideoType::RGBX => Some(stride * height),
+
+        // UYVA: UYVY plus a full-resolution 8-bit alpha plane.
+        FourCCVideoType::UYVA => Some(stride * height + width * height),
+
+        // P216 / PA16: 16-bit planar luma plane plus a same-size chroma/alpha plane.
+        FourCCVideoType::P216 | FourCCVideoType::PA16 => Some(stride * height * 2),
+
+        // I420 / YV12: 8-bit planar 4:2:0, two quarter-size chroma planes.
+        FourCCVideoType::I420 | FourCCVideoType::YV12 => {
+            Some(stride * height + 2 * ((stride / 2) * (height / 2)))
+        },
+
+        // NV12: 8-bit Y plane plus a half-height interleaved UV plane.
+        FourCCVideoType::NV12 => Some(stride * height + stride * (height / 2)),
+    }
+}
+
+/// Number of interleaved channels per pixel for FourCCs that are a single plane of
+/// interleaved samples, or `None` for planar layouts that can't be sliced into
+/// `(height, width, channels)` without losing or misaligning data.
+fn packed_channels(four_cc: u32) -> Option<usize> {
+    match four_cc {
+        0x59565955 /* UYVY */ => Some(2),
+        0x41524742 /* BGRA */ | 0x41424752 /* RGBA */ | 0x58524742 /* BGRX */
+        | 0x58424752 /* RGBX */ => Some(4),
+        _ => None,
+    }
+}
+
+/// Python class representing an NDI video frame
+///
+/// When created with `zero_copy=True` on `receive_frame`, this also keeps the owning
+/// SDK frame alive so `__buffer__` can hand out a view directly onto `p_data()` instead
+/// of a `PyBytes` copy. Drop of the SDK frame is deferred by CPython's own refcounting:
+/// filling `Py_buffer.obj` with this object keeps it alive for as long as any
+/// `memoryview` onto it exists. `view_count` only counts outstanding views; it is not
+/// what keeps the frame alive.
+#[pyclass(unsendable)]
 struct NdiVideoFrame {
     #[pyo3(get)]
     width: u32,
-    
+
     #[pyo3(get)]
     height: u32,
-    
+
     #[pyo3(get)]
     frame_rate_n: u32,
-    
+
     #[pyo3(get)]
     frame_rate_d: u32,
-    
+
     #[pyo3(get)]
     timecode: i64,
-    
+
     #[pyo3(get)]
     data_size: usize,
-    
+
     // We're keeping the frame data as a reference inside a PyBytes object
     data: Option<Py<PyBytes>>,
-    
+
     // FourCC video format
     #[pyo3(get)]
     four_cc: u32,
+
+    // Zero-copy path: the SDK frame backing `__buffer__`, kept alive until released.
+    zero_copy_frame: Option<ndi::VideoData>,
+    view_count: AtomicUsize,
 }
 
 #[pymethods]
@@ -69,6 +187,8 @@ impl NdiVideoFrame {
             data_size,
             data,
             four_cc,
+            zero_copy_frame: None,
+            view_count: AtomicUsize::new(0),
         }
     }
 
@@ -76,7 +196,7 @@ impl NdiVideoFrame {
     fn get_data(&self, _py: Python<'_>) -> Option<Py<PyBytes>> {
         self.data.clone()
     }
-    
+
     /// Get the FourCC format as a string
     fn get_four_cc_name(&self) -> String {
         match self.four_cc {
@@ -94,28 +214,152 @@ impl NdiVideoFrame {
             _ => format!("Unknown (0x{:08X})", self.four_cc),
         }
     }
+
+    /// Expose the frame's pixel buffer as a zero-copy `numpy.ndarray` of shape
+    /// `(height, width, channels)`, for feeding directly into OpenCV/Pillow pipelines.
+    /// Only available on frames created with `zero_copy=True`, and only for formats that
+    /// are a single plane of interleaved pixels (UYVY/BGRA/RGBA/BGRX/RGBX); planar formats
+    /// like NV12/I420/YV12/P216/PA16/UYVA can't be represented as one `(h, w, c)` array and
+    /// raise instead of silently handing back a view onto only part of the buffer. The
+    /// returned array borrows the SDK's memory and keeps this frame alive for as long as
+    /// it exists.
+    fn as_numpy<'py>(slf: &'py PyCell<Self>, _py: Python<'py>) -> PyResult<&'py PyArray3<u8>> {
+        let frame_ref = slf.borrow();
+        let frame = frame_ref.zero_copy_frame.as_ref().ok_or_else(|| {
+            PyRuntimeError::new_err(
+                "This frame was not created with zero_copy=True and has no backing SDK buffer",
+            )
+        })?;
+
+        let channels = packed_channels(frame_ref.four_cc).ok_or_else(|| {
+            PyRuntimeError::new_err(
+                "This frame's pixel format is planar (e.g. NV12/I420/YV12/P216/PA16/UYVA) and \
+                 can't be represented as a single (height, width, channels) array; use the \
+                 buffer protocol directly if you need the raw planes",
+            )
+        })?;
+
+        let stride = frame
+            .line_stride_in_bytes()
+            .ok_or_else(|| PyRuntimeError::new_err("Frame has no line stride"))? as usize;
+        let width = frame_ref.width as usize;
+        // Rows actually in the buffer: half of `height` for a single-field frame.
+        let height = frame_ref.data_size / stride.max(1);
+
+        if width == 0 || stride != width * channels {
+            return Err(PyRuntimeError::new_err(
+                "Frame stride does not match width * channels for this pixel format",
+            ));
+        }
+
+        let shape = (height, width, channels);
+        let view = unsafe { ArrayView3::from_shape_ptr(shape, frame.p_data() as *const u8) };
+
+        // SAFETY: `view` borrows memory owned by the SDK frame kept alive in `slf`; tying
+        // the returned array's lifetime to `slf` keeps that memory valid for as long as
+        // the array exists.
+        Ok(unsafe { PyArray3::borrow_from_array(&view, slf) })
+    }
+
+    unsafe fn __getbuffer__(
+        slf: &PyCell<Self>,
+        view: *mut pyo3::ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        if view.is_null() {
+            return Err(PyBufferError::new_err("View is null"));
+        }
+        if (flags & pyo3::ffi::PyBUF_WRITABLE) == pyo3::ffi::PyBUF_WRITABLE {
+            return Err(PyBufferError::new_err(
+                "NDI video frames are read-only; request a non-writable buffer",
+            ));
+        }
+
+        let frame_ref = slf.borrow();
+        let frame = frame_ref.zero_copy_frame.as_ref().ok_or_else(|| {
+            PyBufferError::new_err(
+                "This frame was not created with zero_copy=True and has no backing SDK buffer",
+            )
+        })?;
+        let len = video_frame_data_size(frame).ok_or_else(|| {
+            PyBufferError::new_err("Unrecognized video format; cannot expose a zero-copy buffer")
+        })?;
+
+        let result = pyo3::ffi::PyBuffer_FillInfo(
+            view,
+            slf.as_ptr(),
+            frame.p_data() as *mut std::os::raw::c_void,
+            len as isize,
+            1, // read-only
+            flags,
+        );
+        if result != 0 {
+            return Err(PyErr::fetch(slf.py()));
+        }
+
+        frame_ref.view_count.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    unsafe fn __releasebuffer__(&self, _view: *mut pyo3::ffi::Py_buffer) {
+        self.view_count.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl NdiVideoFrame {
+    /// Build a video frame that exposes its pixel data through the buffer protocol
+    /// instead of a `PyBytes` copy, for `receive_frame(zero_copy=True)`.
+    fn from_zero_copy(
+        width: u32,
+        height: u32,
+        frame_rate_n: u32,
+        frame_rate_d: u32,
+        timecode: i64,
+        data_size: usize,
+        four_cc: u32,
+        frame: ndi::VideoData,
+    ) -> Self {
+        NdiVideoFrame {
+            width,
+            height,
+            frame_rate_n,
+            frame_rate_d,
+            timecode,
+            data_size,
+            data: None,
+            four_cc,
+            zero_copy_frame: Some(frame),
+            view_count: AtomicUsize::new(0),
+        }
+    }
 }
 
 /// Python class representing an NDI audio frame
-#[pyclass]
+///
+/// See `NdiVideoFrame` for how the `zero_copy_frame`/`view_count` pair back the buffer
+/// protocol path used by `receive_frame(zero_copy=True)`.
+#[pyclass(unsendable)]
 struct NdiAudioFrame {
     #[pyo3(get)]
     sample_rate: u32,
-    
+
     #[pyo3(get)]
     num_channels: u32,
-    
+
     #[pyo3(get)]
     num_samples: u32,
-    
+
     #[pyo3(get)]
     timecode: i64,
-    
+
     #[pyo3(get)]
     data_size: usize,
-    
+
     // We're keeping the audio data as a reference inside a PyBytes object
     data: Option<Py<PyBytes>>,
+
+    zero_copy_frame: Option<ndi::AudioData>,
+    view_count: AtomicUsize,
 }
 
 #[pymethods]
@@ -137,6 +381,8 @@ impl NdiAudioFrame {
             timecode,
             data_size,
             data,
+            zero_copy_frame: None,
+            view_count: AtomicUsize::new(0),
         }
     }
 
@@ -144,6 +390,92 @@ impl NdiAudioFrame {
     fn get_data(&self, _py: Python<'_>) -> Option<Py<PyBytes>> {
         self.data.clone()
     }
+
+    /// Expose the frame's sample buffer as a zero-copy `numpy.ndarray` of shape
+    /// `(channels, samples)`, float32. Only available on frames created with
+    /// `zero_copy=True`; the returned array borrows the SDK's memory and keeps this
+    /// frame alive for as long as it exists.
+    fn as_numpy<'py>(slf: &'py PyCell<Self>, _py: Python<'py>) -> PyResult<&'py PyArray2<f32>> {
+        let frame_ref = slf.borrow();
+        let frame = frame_ref.zero_copy_frame.as_ref().ok_or_else(|| {
+            PyRuntimeError::new_err(
+                "This frame was not created with zero_copy=True and has no backing SDK buffer",
+            )
+        })?;
+
+        let shape = (frame_ref.num_channels as usize, frame_ref.num_samples as usize);
+        let view = unsafe { ArrayView2::from_shape_ptr(shape, frame.p_data() as *const f32) };
+
+        // SAFETY: see `NdiVideoFrame::as_numpy` — `slf` keeps the SDK frame alive for as
+        // long as the returned array exists.
+        Ok(unsafe { PyArray2::borrow_from_array(&view, slf) })
+    }
+
+    unsafe fn __getbuffer__(
+        slf: &PyCell<Self>,
+        view: *mut pyo3::ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        if view.is_null() {
+            return Err(PyBufferError::new_err("View is null"));
+        }
+        if (flags & pyo3::ffi::PyBUF_WRITABLE) == pyo3::ffi::PyBUF_WRITABLE {
+            return Err(PyBufferError::new_err(
+                "NDI audio frames are read-only; request a non-writable buffer",
+            ));
+        }
+
+        let frame_ref = slf.borrow();
+        let frame = frame_ref.zero_copy_frame.as_ref().ok_or_else(|| {
+            PyBufferError::new_err(
+                "This frame was not created with zero_copy=True and has no backing SDK buffer",
+            )
+        })?;
+        let len = frame_ref.data_size;
+
+        let result = pyo3::ffi::PyBuffer_FillInfo(
+            view,
+            slf.as_ptr(),
+            frame.p_data() as *mut std::os::raw::c_void,
+            len as isize,
+            1, // read-only
+            flags,
+        );
+        if result != 0 {
+            return Err(PyErr::fetch(slf.py()));
+        }
+
+        frame_ref.view_count.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    unsafe fn __releasebuffer__(&self, _view: *mut pyo3::ffi::Py_buffer) {
+        self.view_count.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl NdiAudioFrame {
+    /// Build an audio frame that exposes its sample data through the buffer protocol
+    /// instead of a `PyBytes` copy, for `receive_frame(zero_copy=True)`.
+    fn from_zero_copy(
+        sample_rate: u32,
+        num_channels: u32,
+        num_samples: u32,
+        timecode: i64,
+        data_size: usize,
+        frame: ndi::AudioData,
+    ) -> Self {
+        NdiAudioFrame {
+            sample_rate,
+            num_channels,
+            num_samples,
+            timecode,
+            data_size,
+            data: None,
+            zero_copy_frame: Some(frame),
+            view_count: AtomicUsize::new(0),
+        }
+    }
 }
 
 /// Python class representing an NDI metadata frame
@@ -172,26 +504,59 @@ impl NdiMetadataFrame {
 struct NdiReceiver {
     receiver: Option<ndi::recv::Recv>,
     connected_source: Option<String>,
+    bandwidth: RecvBandwidth,
+    allow_video_fields: bool,
+    color_format: RecvColorFormat,
+}
+
+impl NdiReceiver {
+    /// Build a fresh `ndi::recv::Recv` using the settings this receiver was created with.
+    fn build_recv(
+        bandwidth: RecvBandwidth,
+        allow_video_fields: bool,
+        color_format: RecvColorFormat,
+    ) -> PyResult<ndi::recv::Recv> {
+        ndi::recv::RecvBuilder::new()
+            .bandwidth(bandwidth.into())
+            .allow_video_fields(allow_video_fields)
+            .color_format(color_format.into())
+            .build()
+            .map_err(|_| PyRuntimeError::new_err("Failed to create NDI receiver"))
+    }
 }
 
 #[pymethods]
 impl NdiReceiver {
+    /// Create a new receiver.
+    ///
+    /// Args:
+    ///     bandwidth: tuning mode for the incoming stream (default: Highest)
+    ///     allow_video_fields: allow separate interlaced fields rather than full frames
+    ///         (default: true)
+    ///     color_format: preferred pixel format for received video (default: Fastest)
     #[new]
-    fn new() -> PyResult<Self> {
+    #[pyo3(signature = (bandwidth = RecvBandwidth::Highest, allow_video_fields = true, color_format = RecvColorFormat::Fastest))]
+    fn new(
+        bandwidth: RecvBandwidth,
+        allow_video_fields: bool,
+        color_format: RecvColorFormat,
+    ) -> PyResult<Self> {
+        // Make sure the SDK library itself could be found before asking the `ndi` crate
+        // to initialize against it, so a missing install raises a diagnosable error.
+        crate::runtime::ensure_loaded()?;
+
         // Initialize NDI if not already initialized
         match ndi::initialize() {
             Ok(_) => {
-                // Create an unconnected receiver
-                let recv_builder = ndi::recv::RecvBuilder::new();
-                let recv_create = recv_builder.build();
-                
-                match recv_create {
-                    Ok(receiver) => Ok(NdiReceiver { 
-                        receiver: Some(receiver),
-                        connected_source: None,
-                    }),
-                    Err(_) => Err(PyRuntimeError::new_err("Failed to create NDI receiver")),
-                }
+                // Create an unconnected receiver with the requested tuning
+                let receiver = Self::build_recv(bandwidth, allow_video_fields, color_format)?;
+                Ok(NdiReceiver {
+                    receiver: Some(receiver),
+                    connected_source: None,
+                    bandwidth,
+                    allow_video_fields,
+                    color_format,
+                })
             },
             Err(_) => Err(PyRuntimeError::new_err(
                 "Failed to initialize NDI runtime. Make sure the NDI SDK is installed on your system.",
@@ -200,38 +565,56 @@ impl NdiReceiver {
     }
 
     /// Connect to an NDI source
-    fn connect_to_source(&mut self, source_name: &str) -> PyResult<()> {
-        let receiver = match &mut self.receiver {
-            Some(r) => r,
-            None => return Err(PyRuntimeError::new_err("Receiver is not initialized")),
-        };
-        
-        // Find the source with the given name
-        let find_create = ndi::find::FindBuilder::new().build();
-        match find_create {
-            Ok(finder) => {
-                // Look for sources with a reasonable timeout
-                let sources_result = finder.current_sources(3000);
-                
-                match sources_result {
-                    Ok(sources) => {
-                        // Find the source with the matching name
-                        for source in sources.iter() {
-                            if source.get_name() == source_name {
-                                // Connect to this source
-                                receiver.connect(source);
-                                self.connected_source = Some(source_name.to_string());
-                                return Ok(());
-                            }
-                        }
-                        
-                        // If we get here, the source was not found
-                        Err(PyRuntimeError::new_err(format!("Source not found: {}", source_name)))
-                    },
-                    Err(_) => Err(PyRuntimeError::new_err("Timeout while searching for sources")),
+    ///
+    /// Args:
+    ///     source_name: name of the NDI source to connect to
+    ///     show_local_sources: include sources originating on this machine (default: true)
+    ///     groups: comma-separated list of NDI groups to restrict discovery to
+    ///     extra_ips: comma-separated list of unicast IPs/hostnames to probe in addition
+    ///                to mDNS discovery, for subnets where multicast does not propagate
+    #[pyo3(signature = (source_name, show_local_sources = true, groups = None, extra_ips = None))]
+    fn connect_to_source(
+        &mut self,
+        source_name: &str,
+        show_local_sources: bool,
+        groups: Option<String>,
+        extra_ips: Option<String>,
+    ) -> PyResult<()> {
+        if self.receiver.is_none() {
+            return Err(PyRuntimeError::new_err("Receiver is not initialized"));
+        }
+
+        // Find the source with the given name, honouring the same discovery settings
+        // that `NdiFinder` exposes.
+        let finder = crate::discovery::build_finder(show_local_sources, groups, extra_ips)?;
+
+        // Look for sources with a reasonable timeout
+        let sources_result = finder.current_sources(3000);
+
+        match sources_result {
+            Ok(sources) => {
+                // Find the source with the matching name
+                for source in sources.iter() {
+                    if source.get_name() == source_name {
+                        // Rebuild the receiver with the same tuning settings so
+                        // reconnecting never silently drops the requested bandwidth,
+                        // field handling, or color format.
+                        let mut receiver = Self::build_recv(
+                            self.bandwidth,
+                            self.allow_video_fields,
+                            self.color_format,
+                        )?;
+                        receiver.connect(source);
+                        self.receiver = Some(receiver);
+                        self.connected_source = Some(source_name.to_string());
+                        return Ok(());
+                    }
                 }
+
+                // If we get here, the source was not found
+                Err(PyRuntimeError::new_err(format!("Source not found: {}", source_name)))
             },
-            Err(_) => Err(PyRuntimeError::new_err("Failed to create NDI finder")),
+            Err(_) => Err(PyRuntimeError::new_err("Timeout while searching for sources")),
         }
     }
 
@@ -242,7 +625,21 @@ impl NdiReceiver {
     }
 
     /// Receive a frame with a timeout
-    fn receive_frame(&mut self, timeout_ms: Option<u32>, py: Python<'_>) -> PyResult<(FrameType, PyObject)> {
+    ///
+    /// Args:
+    ///     timeout_ms: how long to wait for a frame (default: 1000)
+    ///     zero_copy: when true, video/audio frames expose their pixel/sample data via
+    ///         the buffer protocol directly onto the SDK's own memory instead of
+    ///         copying into a `bytes` object. The SDK frame is kept alive for as long as
+    ///         a `memoryview` onto it exists. Defaults to false for the safer copying
+    ///         behavior.
+    #[pyo3(signature = (timeout_ms = None, zero_copy = false))]
+    fn receive_frame(
+        &mut self,
+        timeout_ms: Option<u32>,
+        zero_copy: bool,
+        py: Python<'_>,
+    ) -> PyResult<(FrameType, PyObject)> {
         let receiver = match &mut self.receiver {
             Some(r) => r,
             None => return Err(PyRuntimeError::new_err("Receiver is not initialized")),
@@ -284,38 +681,41 @@ impl NdiReceiver {
                     let frame_rate_d = video.frame_rate_d() as u32;
                     let timecode = video.timecode();
                     
-                    // Get the raw data pointer and size
-                    let p_data = video.p_data();
-                    let mut data_size = 0;
-                    
-                    // Determine the frame data size based on the format
-                    if let Some(stride) = video.line_stride_in_bytes() {
-                        data_size = (stride * height) as usize;
-                    } else if let Some(size) = video.data_size_in_bytes() {
-                        data_size = size as usize;
+                    // Determine the frame data size from its FourCC and field mode; refuse
+                    // to expose bytes for a frame that can't be sized rather than guessing.
+                    let data_size = video_frame_data_size(&video);
+                    let four_cc = video.four_cc() as u32;
+
+                    let frame = if zero_copy {
+                        // Keep the SDK frame alive and back it with a buffer-protocol view.
+                        NdiVideoFrame::from_zero_copy(
+                            width,
+                            height,
+                            frame_rate_n,
+                            frame_rate_d,
+                            timecode,
+                            data_size.unwrap_or(0),
+                            four_cc,
+                            video,
+                        )
                     } else {
-                        // If neither is available, calculate a reasonable default size
-                        // For UYVY format, we need 2 bytes per pixel
-                        data_size = (width as usize * height as usize * 2) as usize;
-                    }
-                    
-                    // Create a PyBytes object with the video data
-                    let data_bytes = unsafe {
-                        PyBytes::from_ptr(py, p_data as *const u8, data_size)
+                        let data_bytes: Option<Py<PyBytes>> = data_size.map(|size| {
+                            // Create a PyBytes object with the video data
+                            unsafe { PyBytes::from_ptr(py, video.p_data() as *const u8, size) }.into()
+                        });
+
+                        NdiVideoFrame::new(
+                            width,
+                            height,
+                            frame_rate_n,
+                            frame_rate_d,
+                            timecode,
+                            data_size.unwrap_or(0),
+                            data_bytes,
+                            four_cc,
+                        )
                     };
-                    
-                    // Create an NdiVideoFrame object with the frame data
-                    let frame = NdiVideoFrame::new(
-                        width,
-                        height,
-                        frame_rate_n,
-                        frame_rate_d,
-                        timecode,
-                        data_size,
-                        Some(data_bytes.into_py(py)),
-                        video.four_cc() as u32,
-                    );
-                    
+
                     return Ok((frame_type_py, Py::new(py, frame)?.into_py(py)));
                 }
             },
@@ -329,22 +729,33 @@ impl NdiReceiver {
                     
                     // Get the audio data size (samples * channels * 4 bytes per float)
                     let data_size = (num_samples as usize * num_channels as usize * 4) as usize;
-                    
-                    // Create a PyBytes object with the audio data
-                    let data_bytes = unsafe {
-                        PyBytes::from_ptr(py, audio.p_data() as *const u8, data_size)
+
+                    let frame = if zero_copy {
+                        // Keep the SDK frame alive and back it with a buffer-protocol view.
+                        NdiAudioFrame::from_zero_copy(
+                            sample_rate,
+                            num_channels,
+                            num_samples,
+                            timecode,
+                            data_size,
+                            audio,
+                        )
+                    } else {
+                        // Create a PyBytes object with the audio data
+                        let data_bytes = unsafe {
+                            PyBytes::from_ptr(py, audio.p_data() as *const u8, data_size)
+                        };
+
+                        NdiAudioFrame::new(
+                            sample_rate,
+                            num_channels,
+                            num_samples,
+                            timecode,
+                            data_size,
+                            Some(data_bytes.into_py(py)),
+                        )
                     };
-                    
-                    // Create an NdiAudioFrame object with the frame data
-                    let frame = NdiAudioFrame::new(
-                        sample_rate,
-                        num_channels,
-                        num_samples,
-                        timecode,
-                        data_size,
-                        Some(data_bytes.into_py(py)),
-                    );
-                    
+
                     return Ok((frame_type_py, Py::new(py, frame)?.into_py(py)));
                 }
             },
@@ -381,6 +792,8 @@ impl NdiReceiver {
 /// Register receiver-related Python functions and classes
 pub fn register_receiver_functions(m: &PyModule) -> PyResult<()> {
     m.add_class::<FrameType>()?;
+    m.add_class::<RecvBandwidth>()?;
+    m.add_class::<RecvColorFormat>()?;
     m.add_class::<NdiVideoFrame>()?;
     m.add_class::<NdiAudioFrame>()?;
     m.add_class::<NdiMetadataFrame>()?;
@@ -0,0 +1,194 @@
+// src/runtime.rs
+//
+// Locates and dynamically loads the NDI runtime library, caching a successful load so
+// repeated lookups (and `is_sdk_available`/`get_sdk_version`) are cheap, and gives a clear,
+// actionable error (with the paths searched) when it can't be found.
+//
+// Note this is a *separate* lookup from the SDK library the `ndi` crate itself links
+// against: `ndi`'s build script links against a bundled `libndi.so.4` at build time
+// (`DT_NEEDED`, resolved by the OS loader before any Rust code runs), so importing this
+// module already requires that exact library to be resolvable regardless of anything
+// below. What this module probes for with `libloading` is the newer `libndi.so.5` the SDK
+// installer ships. It reports a live version string via `get_sdk_version`/`NdiVersionInfo`,
+// and through `ensure_loaded` the `NdiFinder`/`NdiReceiver`/`NdiSender` constructors raise
+// `NdiSdkNotFoundError` when it can't be found. It does not gate, and cannot rescue,
+// whether the module imports at all.
+
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use std::env;
+use std::ffi::CStr;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use libloading::Library;
+
+pyo3::create_exception!(ndirust_py, NdiSdkNotFoundError, PyException);
+
+#[cfg(target_os = "windows")]
+const LIB_FILENAME: &str = "Processing.NDI.Lib.x64.dll";
+#[cfg(target_os = "macos")]
+const LIB_FILENAME: &str = "libndi.dylib";
+#[cfg(all(unix, not(target_os = "macos")))]
+const LIB_FILENAME: &str = "libndi.so.5";
+
+/// A successfully located and loaded NDI runtime library.
+struct LoadedSdk {
+    library: Library,
+    path: PathBuf,
+}
+
+/// Directories to search for the NDI runtime library, in priority order: an explicit
+/// override, the versioned `NDI_RUNTIME_DIR_V*` environment variables the SDK installer
+/// sets (current `V5` first, then the legacy `V4`/`V3` names), the process's current
+/// working directory, and finally the OS's default install locations.
+fn candidate_dirs(explicit: Option<&str>) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+
+    if let Some(explicit) = explicit {
+        dirs.push(PathBuf::from(explicit));
+    }
+
+    for var in ["NDI_RUNTIME_DIR_V5", "NDI_RUNTIME_DIR_V4", "NDI_RUNTIME_DIR_V3"] {
+        if let Ok(value) = env::var(var) {
+            dirs.push(PathBuf::from(value));
+        }
+    }
+
+    if let Ok(cwd) = env::current_dir() {
+        dirs.push(cwd);
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        if let Ok(program_files) = env::var("ProgramFiles") {
+            dirs.push(PathBuf::from(program_files).join("NDI").join("NDI 5 Runtime").join("v5"));
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        dirs.push(PathBuf::from("/Library/NDI SDK for Apple/lib/macOS"));
+        dirs.push(PathBuf::from("/usr/local/lib"));
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        dirs.push(PathBuf::from("/usr/lib"));
+        dirs.push(PathBuf::from("/usr/local/lib"));
+    }
+
+    dirs
+}
+
+/// Search the candidate directories for the runtime library, falling back to letting the
+/// OS resolve the bare library name through its own search paths (`LD_LIBRARY_PATH`,
+/// `PATH`, etc). Returns the paths tried on failure so the caller can report them.
+fn locate_and_load(explicit: Option<&str>) -> Result<LoadedSdk, Vec<PathBuf>> {
+    let mut tried = Vec::new();
+
+    for dir in candidate_dirs(explicit) {
+        let candidate = dir.join(LIB_FILENAME);
+        tried.push(candidate.clone());
+        if let Ok(library) = unsafe { Library::new(&candidate) } {
+            return Ok(LoadedSdk { library, path: candidate });
+        }
+    }
+
+    let bare = PathBuf::from(LIB_FILENAME);
+    tried.push(bare.clone());
+    match unsafe { Library::new(LIB_FILENAME) } {
+        Ok(library) => Ok(LoadedSdk { library, path: bare }),
+        Err(_) => Err(tried),
+    }
+}
+
+/// Cache for a successfully loaded SDK, shared between the no-argument lookup and
+/// `resolve()`. Only the success case is cached: a failed lookup is retried on every call,
+/// so a later successful `resolve(Some(explicit_dir))` (e.g. via `utils.initialize_ndi`)
+/// is picked up by a subsequent no-argument `ensure_loaded()` instead of replaying a
+/// once-failed search forever.
+static LOADED_SDK: OnceLock<LoadedSdk> = OnceLock::new();
+
+/// The no-argument runtime load, cached for the life of the process once it succeeds;
+/// this backs `is_sdk_available`/`get_sdk_version`/`ensure_loaded`, which don't take an
+/// explicit override path.
+fn loaded() -> Result<&'static LoadedSdk, Vec<PathBuf>> {
+    if let Some(sdk) = LOADED_SDK.get() {
+        return Ok(sdk);
+    }
+    locate_and_load(None).map(|sdk| LOADED_SDK.get_or_init(|| sdk))
+}
+
+fn searched_paths_message(tried: &[PathBuf]) -> String {
+    let searched = tried.iter().map(|p| p.display().to_string()).collect::<Vec<_>>().join(", ");
+    format!("Could not locate the NDI runtime library. Searched: {}", searched)
+}
+
+/// Raise `NdiSdkNotFoundError` carrying the searched paths if the runtime couldn't be
+/// loaded. Other constructors in this crate can call this before touching `ndi::*` to
+/// turn an opaque SDK failure into an actionable one.
+pub fn ensure_loaded() -> PyResult<()> {
+    match loaded() {
+        Ok(_) => Ok(()),
+        Err(tried) => Err(NdiSdkNotFoundError::new_err(searched_paths_message(&tried))),
+    }
+}
+
+/// Locate and load the NDI runtime from an explicit directory (if given) or the standard
+/// search locations, returning the resolved library path on success. A successful load is
+/// folded into the same process-wide cache `ensure_loaded`/`is_sdk_available` use, so e.g.
+/// calling `utils.initialize_ndi(runtime_dir=...)` makes the SDK visible to later
+/// no-argument lookups too, instead of only to this call. If a library is already cached
+/// (for example by the import-time probe), its path is returned and `explicit` is not
+/// searched.
+pub fn resolve(explicit: Option<&str>) -> PyResult<PathBuf> {
+    if let Some(sdk) = LOADED_SDK.get() {
+        return Ok(sdk.path.clone());
+    }
+    locate_and_load(explicit)
+        .map(|sdk| LOADED_SDK.get_or_init(|| sdk).path.clone())
+        .map_err(|tried| NdiSdkNotFoundError::new_err(searched_paths_message(&tried)))
+}
+
+/// Check whether the NDI SDK runtime library could be located and loaded.
+#[pyfunction]
+fn is_sdk_available() -> bool {
+    loaded().is_ok()
+}
+
+/// The loaded runtime's version string, read from the library's own `NDIlib_version`
+/// export, or `None` if it isn't loaded or doesn't expose one.
+pub fn version_string() -> Option<String> {
+    let sdk = loaded().ok()?;
+    unsafe {
+        let ndi_version: libloading::Symbol<unsafe extern "C" fn() -> *const std::os::raw::c_char> =
+            sdk.library.get(b"NDIlib_version\0").ok()?;
+        let ptr = ndi_version();
+        if ptr.is_null() {
+            return None;
+        }
+        Some(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+    }
+}
+
+/// The path of the runtime library that was actually loaded, or `None` if loading failed.
+pub fn library_path() -> Option<PathBuf> {
+    loaded().ok().map(|sdk| sdk.path.clone())
+}
+
+/// Get the loaded NDI runtime's version string, or `None` if it isn't loaded or doesn't
+/// expose one.
+#[pyfunction]
+fn get_sdk_version() -> Option<String> {
+    version_string()
+}
+
+/// Register the runtime-loader functions and the `NdiSdkNotFoundError` exception type.
+pub fn register_runtime_functions(py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(is_sdk_available, m)?)?;
+    m.add_function(wrap_pyfunction!(get_sdk_version, m)?)?;
+    m.add("NdiSdkNotFoundError", py.get_type::<NdiSdkNotFoundError>())?;
+
+    Ok(())
+}
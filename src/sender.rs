@@ -2,9 +2,43 @@
 
 use pyo3::prelude::*;
 use ndi;
-use pyo3::exceptions::PyRuntimeError;
+use pyo3::exceptions::{PyNotImplementedError, PyRuntimeError, PyValueError};
 use pyo3::types::PyBytes;
 
+/// Output pixel format exposed to Python for `send_test_pattern`/`send_video_frame`.
+#[pyclass]
+#[derive(Clone, Copy)]
+pub enum VideoPixelFormat {
+    UYVY = 0,
+    BGRA = 1,
+    RGBA = 2,
+    BGRX = 3,
+    RGBX = 4,
+}
+
+impl VideoPixelFormat {
+    fn fourcc(self) -> ndi::FourCCVideoType {
+        match self {
+            VideoPixelFormat::UYVY => ndi::FourCCVideoType::UYVY,
+            VideoPixelFormat::BGRA => ndi::FourCCVideoType::BGRA,
+            VideoPixelFormat::RGBA => ndi::FourCCVideoType::RGBA,
+            VideoPixelFormat::BGRX => ndi::FourCCVideoType::BGRX,
+            VideoPixelFormat::RGBX => ndi::FourCCVideoType::RGBX,
+        }
+    }
+
+    /// Bytes per pixel for this format, used to derive a default stride.
+    fn bytes_per_pixel(self) -> u32 {
+        match self {
+            VideoPixelFormat::UYVY => 2,
+            VideoPixelFormat::BGRA
+            | VideoPixelFormat::RGBA
+            | VideoPixelFormat::BGRX
+            | VideoPixelFormat::RGBX => 4,
+        }
+    }
+}
+
 /// Python class for creating and sending NDI video frames
 #[pyclass(unsendable)]  // Mark as unsendable to avoid thread safety concerns
 struct NdiSender {
@@ -16,6 +50,10 @@ struct NdiSender {
 impl NdiSender {
     #[new]
     fn new(name: &str) -> PyResult<Self> {
+        // Make sure the SDK library itself could be found before asking the `ndi` crate
+        // to initialize against it, so a missing install raises a diagnosable error.
+        crate::runtime::ensure_loaded()?;
+
         // Initialize NDI if not already initialized
         match ndi::initialize() {
             Ok(_) => {
@@ -38,112 +76,231 @@ impl NdiSender {
     }
 
     /// Send a test pattern video frame
-    /// 
+    ///
     /// Args:
     ///     width: Width of the test pattern (default: 1280)
     ///     height: Height of the test pattern (default: 720)
     ///     fps_n: Framerate numerator (default: 30)
     ///     fps_d: Framerate denominator (default: 1)
-    #[pyo3(signature = (width=1280, height=720, fps_n=30, fps_d=1))]
-    fn send_test_pattern(&self, width: u32, height: u32, fps_n: u32, fps_d: u32) -> PyResult<()> {
+    ///     timecode: Frame timecode in 100ns units, or 0 to let the SDK synthesize one (default: 0)
+    ///     fourcc: Pixel format to encode the pattern in (default: UYVY)
+    ///     stride: Explicit bytes-per-line override; defaults to width * bytes-per-pixel
+    #[pyo3(signature = (width=1280, height=720, fps_n=30, fps_d=1, timecode=0, fourcc=VideoPixelFormat::UYVY, stride=None))]
+    fn send_test_pattern(
+        &self,
+        width: u32,
+        height: u32,
+        fps_n: u32,
+        fps_d: u32,
+        timecode: i64,
+        fourcc: VideoPixelFormat,
+        stride: Option<u32>,
+    ) -> PyResult<()> {
         let sender = match &self.sender {
             Some(s) => s,
             None => return Err(PyRuntimeError::new_err("Sender is not initialized")),
         };
-        
-        // Create a simple color test pattern in UYVY format (2 bytes per pixel)
-        let data_size = (width * height * 2) as usize;
+
+        let bytes_per_pixel = fourcc.bytes_per_pixel();
+        let line_stride = stride.unwrap_or(width * bytes_per_pixel);
+        let data_size = (line_stride * height) as usize;
         let mut data = vec![0u8; data_size];
-        
-        // Create a colorful test pattern
-        for y in 0..height {
-            for x in 0..width {
-                let index = ((y * width + x) * 2) as usize;
-                if index + 1 < data_size {
-                    // U and V values for color
-                    data[index] = ((x * 255) / width) as u8;     // U: blue-difference chroma
-                    data[index + 1] = ((y * 255) / height) as u8; // Y: luma
-                    // Additional Y and V values
-                    if x % 2 == 0 && index + 3 < data_size {
-                        data[index + 2] = 128;   // V: red-difference chroma
-                        data[index + 3] = 235;   // Y: luma (white)
+
+        // Create a colorful test pattern in the requested pixel format
+        match fourcc {
+            VideoPixelFormat::UYVY => {
+                for y in 0..height {
+                    for x in 0..width {
+                        let index = (y * line_stride + x * 2) as usize;
+                        if index + 1 < data_size {
+                            // U and V values for color
+                            data[index] = ((x * 255) / width) as u8;     // U: blue-difference chroma
+                            data[index + 1] = ((y * 255) / height) as u8; // Y: luma
+                            // Additional Y and V values
+                            if x % 2 == 0 && index + 3 < data_size {
+                                data[index + 2] = 128;   // V: red-difference chroma
+                                data[index + 3] = 235;   // Y: luma (white)
+                            }
+                        }
+                    }
+                }
+            },
+            VideoPixelFormat::BGRA | VideoPixelFormat::BGRX => {
+                for y in 0..height {
+                    for x in 0..width {
+                        let index = (y * line_stride + x * 4) as usize;
+                        if index + 3 < data_size {
+                            data[index] = 128;                      // B
+                            data[index + 1] = ((y * 255) / height) as u8; // G
+                            data[index + 2] = ((x * 255) / width) as u8;  // R
+                            data[index + 3] = 255;                  // A/X
+                        }
                     }
                 }
-            }
+            },
+            VideoPixelFormat::RGBA | VideoPixelFormat::RGBX => {
+                for y in 0..height {
+                    for x in 0..width {
+                        let index = (y * line_stride + x * 4) as usize;
+                        if index + 3 < data_size {
+                            data[index] = ((x * 255) / width) as u8;      // R
+                            data[index + 1] = ((y * 255) / height) as u8; // G
+                            data[index + 2] = 128;                  // B
+                            data[index + 3] = 255;                  // A/X
+                        }
+                    }
+                }
+            },
         }
-        
-        // Create a video frame using the VideoData::from_buffer method
-        let fourcc = ndi::FourCCVideoType::UYVY;
-        let frame_format = ndi::FrameFormatType::Progressive;
-        
-        // Calculate stride (bytes per line)
-        let stride = (width * 2) as i32;  // 2 bytes per pixel for UYVY
-        
+
         // Create video frame
         let video_data = ndi::VideoData::from_buffer(
-            width as i32, 
+            width as i32,
             height as i32,
-            fourcc,
+            fourcc.fourcc(),
             fps_n as i32,
             fps_d as i32,
-            frame_format,
-            0, // timecode
-            stride,
+            ndi::FrameFormatType::Progressive,
+            timecode,
+            line_stride as i32,
             None, // metadata
             &mut data
         );
-        
+
         // Send the frame
         sender.send_video(&video_data);
-        
+
         println!("Sent test pattern frame {}x{} @ {}/{} fps", width, height, fps_n, fps_d);
         Ok(())
     }
-    
+
     /// Send custom video frame from raw byte data
-    /// 
+    ///
     /// Args:
     ///     data: Raw video data bytes
     ///     width: Width of the frame
     ///     height: Height of the frame
     ///     fps_n: Framerate numerator (default: 30)
     ///     fps_d: Framerate denominator (default: 1)
-    #[pyo3(signature = (data, width, height, fps_n=30, fps_d=1))]
-    fn send_video_frame(&self, data: &PyBytes, width: u32, height: u32, fps_n: u32, fps_d: u32) -> PyResult<()> {
+    ///     timecode: Frame timecode in 100ns units, or 0 to let the SDK synthesize one (default: 0)
+    ///     fourcc: Pixel format the `data` buffer is laid out in (default: UYVY)
+    ///     stride: Explicit bytes-per-line override; defaults to width * bytes-per-pixel
+    #[pyo3(signature = (data, width, height, fps_n=30, fps_d=1, timecode=0, fourcc=VideoPixelFormat::UYVY, stride=None))]
+    fn send_video_frame(
+        &self,
+        data: &PyBytes,
+        width: u32,
+        height: u32,
+        fps_n: u32,
+        fps_d: u32,
+        timecode: i64,
+        fourcc: VideoPixelFormat,
+        stride: Option<u32>,
+    ) -> PyResult<()> {
         let sender = match &self.sender {
             Some(s) => s,
             None => return Err(PyRuntimeError::new_err("Sender is not initialized")),
         };
-        
+
+        // Default stride is width * bytes-per-pixel for the chosen format
+        let line_stride = stride.unwrap_or(width * fourcc.bytes_per_pixel());
+        let required_len = line_stride as usize * height as usize;
+
         // Extract bytes from PyBytes
         let mut py_bytes = Python::with_gil(|_py| {
             let bytes = data.as_bytes();
             bytes.to_vec()
         });
-        
-        // Calculate stride (bytes per line)
-        let stride = (width * 2) as i32;  // 2 bytes per pixel for UYVY
-        
+
+        if py_bytes.len() < required_len {
+            return Err(PyValueError::new_err(format!(
+                "data is too short for a {}x{} frame at stride {}: need at least {} bytes, got {}",
+                width,
+                height,
+                line_stride,
+                required_len,
+                py_bytes.len()
+            )));
+        }
+
         // Create video frame
         let video_data = ndi::VideoData::from_buffer(
-            width as i32, 
+            width as i32,
             height as i32,
-            ndi::FourCCVideoType::UYVY,
+            fourcc.fourcc(),
             fps_n as i32,
             fps_d as i32,
             ndi::FrameFormatType::Progressive,
-            0, // timecode
-            stride,
+            timecode,
+            line_stride as i32,
             None, // metadata
             &mut py_bytes
         );
-        
+
         // Send the frame
         sender.send_video(&video_data);
-        
+
         Ok(())
     }
-    
+
+    /// Send a planar float32 audio frame
+    ///
+    /// Args:
+    ///     data: Raw planar float32 audio samples, channel-by-channel
+    ///     sample_rate: Audio sample rate in Hz (e.g. 48000)
+    ///     num_channels: Number of audio channels
+    ///     num_samples: Number of samples per channel
+    ///
+    /// Raises:
+    ///     NotImplementedError: the `ndi` crate this is built against (v0.1) exposes
+    ///         `AudioData` with every field private and no buffer-accepting constructor
+    ///         or setter beyond `AudioData::new()`, which builds an empty, unpopulated
+    ///         frame. There is currently no way to attach a caller-supplied sample
+    ///         buffer to an `AudioData` from outside the crate, so this can't be
+    ///         implemented until that crate grows one (or this project vendors a patched
+    ///         copy) — raising here is preferable to silently sending an empty frame.
+    fn send_audio_frame(
+        &self,
+        data: &PyBytes,
+        sample_rate: u32,
+        num_channels: u32,
+        num_samples: u32,
+    ) -> PyResult<()> {
+        if self.sender.is_none() {
+            return Err(PyRuntimeError::new_err("Sender is not initialized"));
+        }
+
+        Err(PyNotImplementedError::new_err(format!(
+            "send_audio_frame is not supported by the installed `ndi` crate: AudioData has \
+             no public way to attach a sample buffer (wanted {} channel(s) of {} sample(s) \
+             at {}Hz from {} byte(s) of data)",
+            num_channels,
+            num_samples,
+            sample_rate,
+            data.as_bytes().len()
+        )))
+    }
+
+    /// Send an NDI metadata frame
+    ///
+    /// Args:
+    ///     xml: XML-formatted metadata string (e.g. source config, custom app state)
+    ///     timecode: Frame timecode in 100ns units, or 0 to let the SDK synthesize one (default: 0)
+    #[pyo3(signature = (xml, timecode=0))]
+    fn send_metadata(&self, xml: &str, timecode: i64) -> PyResult<()> {
+        let sender = match &self.sender {
+            Some(s) => s,
+            None => return Err(PyRuntimeError::new_err("Sender is not initialized")),
+        };
+
+        // A length of 0 tells the SDK to treat `data` as a NUL-terminated string and
+        // compute the length itself, rather than us tracking the exact byte count.
+        let metadata = ndi::MetaData::new(0, timecode, xml.to_string());
+        sender.send_metadata(&metadata);
+
+        Ok(())
+    }
+
     /// Get the name of this NDI sender
     #[getter]
     fn get_name(&self) -> PyResult<String> {
@@ -159,7 +316,8 @@ impl NdiSender {
 
 /// Register sender-related Python functions and classes
 pub fn register_sender_functions(m: &PyModule) -> PyResult<()> {
+    m.add_class::<VideoPixelFormat>()?;
     m.add_class::<NdiSender>()?;
-    
+
     Ok(())
 } 
\ No newline at end of file
@@ -1,38 +1,119 @@
 // src/utils.rs
 
 use pyo3::prelude::*;
-use ndi; // Use the ndi crate directly 
+use ndi; // Use the ndi crate directly
+
+/// Structured NDI runtime version/capability info, for code that needs to branch on
+/// whether it's talking to a v4 or v5 runtime.
+#[pyclass]
+pub struct NdiVersionInfo {
+    /// Raw version string reported by the runtime library itself, if it could be loaded.
+    #[pyo3(get)]
+    version_string: Option<String>,
+
+    /// Major version number parsed out of `version_string` (e.g. 5 for "NDI SDK Version 5.5.3").
+    #[pyo3(get)]
+    major_version: Option<u32>,
+
+    /// Path of the runtime library that was actually loaded.
+    #[pyo3(get)]
+    library_path: Option<String>,
+
+    /// Whether this CPU supports the instruction set the NDI SDK requires.
+    #[pyo3(get)]
+    is_supported_cpu: bool,
+}
+
+#[pymethods]
+impl NdiVersionInfo {
+    fn __repr__(&self) -> String {
+        format!(
+            "NdiVersionInfo(version_string={:?}, major_version={:?}, library_path={:?}, is_supported_cpu={})",
+            self.version_string, self.major_version, self.library_path, self.is_supported_cpu
+        )
+    }
+}
+
+/// Parse the leading major version number out of an NDI version string such as
+/// "NDI SDK Version 5.5.3" or "NDI RUNTIME Version 4.5.1".
+fn parse_major_version(version_string: &str) -> Option<u32> {
+    let digits_start = version_string.find(|c: char| c.is_ascii_digit())?;
+    let digits: String = version_string[digits_start..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse().ok()
+}
 
 /// Register utility functions for NDI API
 pub fn register_utility_functions(m: &PyModule) -> PyResult<()> {
     // Add version information function
     #[pyfunction]
     fn get_ndi_version() -> String {
-        // This is a placeholder since we can't directly get the SDK version
-        // from the Rust API without initializing. Would normally call something like
-        // ndi::version_string() or similar.
-        "NDI SDK (via Rust bindings)".to_string()
+        // Prefer the actual runtime version if the SDK could be located; fall back to a
+        // generic placeholder if it couldn't (e.g. not installed on this machine).
+        crate::runtime::version_string().unwrap_or_else(|| "NDI SDK (via Rust bindings)".to_string())
     }
-    
+
+    /// Get structured version/capability info about the NDI runtime.
+    #[pyfunction]
+    fn get_ndi_version_info() -> NdiVersionInfo {
+        let version_string = crate::runtime::version_string();
+        let major_version = version_string.as_deref().and_then(parse_major_version);
+        let library_path = crate::runtime::library_path().map(|p| p.display().to_string());
+
+        NdiVersionInfo {
+            version_string,
+            major_version,
+            library_path,
+            is_supported_cpu: ndi::is_supported_CPU(),
+        }
+    }
+
     // Add function to check if NDI is supported on this CPU
     #[pyfunction]
     fn is_supported_cpu() -> bool {
         ndi::is_supported_CPU()
     }
-    
-    // Add function to initialize NDI
+
+    /// Initialize NDI, resolving the runtime library the way the reference Rust NDI
+    /// wrapper does: an explicit `runtime_dir`, then the `NDI_RUNTIME_DIR_V5`/legacy
+    /// `NDI_RUNTIME_DIR_V4`/`NDI_RUNTIME_DIR_V3` environment variables, the working
+    /// directory, and finally the OS's default search paths.
+    ///
+    /// Note this locates the `libndi.so.5`-style runtime library used for capability
+    /// reporting (see runtime.rs); it has no bearing on whether `import ndirust_py`
+    /// itself succeeds, since the `ndi` crate already links against its own bundled SDK
+    /// library at build time.
+    ///
+    /// Args:
+    ///     runtime_dir: directory to search first for the NDI runtime library. Not
+    ///         searched if a runtime library is already loaded (for example by the
+    ///         import-time probe); that library's path is returned instead.
+    ///
+    /// Returns the resolved library path on success. Raises `NdiSdkNotFoundError` (listing
+    /// every path that was tried) if the runtime couldn't be found, so deployment problems
+    /// are debuggable from Python instead of silently returning `False`.
     #[pyfunction]
-    fn initialize_ndi() -> PyResult<bool> {
-        match ndi::initialize() {
-            Ok(_) => Ok(true),
-            Err(_) => Ok(false),
-        }
+    #[pyo3(signature = (runtime_dir = None))]
+    fn initialize_ndi(runtime_dir: Option<&str>) -> PyResult<String> {
+        let resolved_path = crate::runtime::resolve(runtime_dir)?;
+
+        ndi::initialize().map_err(|_| {
+            pyo3::exceptions::PyRuntimeError::new_err(
+                "Located the NDI runtime library but ndi::initialize() failed",
+            )
+        })?;
+
+        Ok(resolved_path.display().to_string())
     }
-    
+
     // Register the functions with the module
+    m.add_class::<NdiVersionInfo>()?;
     m.add_function(wrap_pyfunction!(get_ndi_version, m)?)?;
+    m.add_function(wrap_pyfunction!(get_ndi_version_info, m)?)?;
     m.add_function(wrap_pyfunction!(is_supported_cpu, m)?)?;
     m.add_function(wrap_pyfunction!(initialize_ndi, m)?)?;
-    
+
     Ok(())
-} 
\ No newline at end of file
+}
\ No newline at end of file